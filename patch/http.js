@@ -4,10 +4,16 @@ const shimmer = require('./shimmer');
 const http = require('http');
 const https = require('https');
 
-function requestListenerWrapper(original, methods) {
+// mark createServer wrapped by xprofiler, other modules may wrap it too
+const kPatched = Symbol('xprofiler.patched');
+
+// updated by each patchHttp, read by patched servers at request time
+let httpMethods = {};
+
+function requestListenerWrapper(original) {
   return function (req, res) {
     const { addLiveRequest, addCloseRequest, addSentRequest,
-      addRequestTimeout, addHttpStatusCode, patch_http_timeout } = methods;
+      addRequestTimeout, addHttpStatusCode, patch_http_timeout } = httpMethods;
 
     addLiveRequest();
 
@@ -35,15 +41,15 @@ function requestListenerWrapper(original, methods) {
   };
 }
 
-function serverWrapper(methods, original) {
+function serverWrapper(original) {
   return function (opts, requestListener) {
     const args = Array.from(arguments);
     let returned;
 
     if (typeof opts === 'function') {
-      args.splice(0, 1, requestListenerWrapper(opts, methods));
+      args.splice(0, 1, requestListenerWrapper(opts));
     } else if (typeof requestListener === 'function') {
-      args.splice(1, 1, requestListenerWrapper(requestListener, methods));
+      args.splice(1, 1, requestListenerWrapper(requestListener));
     }
 
     returned = original.apply(this, args);
@@ -52,11 +58,23 @@ function serverWrapper(methods, original) {
   };
 }
 
+function patchServer(nodule) {
+  if (nodule.createServer[kPatched]) {
+    return;
+  }
+  const wrapped = shimmer.wrap(nodule, 'createServer', serverWrapper);
+  if (wrapped) {
+    wrapped[kPatched] = true;
+  }
+}
+
 function patchHttp(methods) {
+  httpMethods = methods;
+
   // patch http server
-  shimmer.wrap(http, 'createServer', serverWrapper.bind(this, methods));
+  patchServer(http);
   // patch https server
-  shimmer.wrap(https, 'createServer', serverWrapper.bind(this, methods));
+  patchServer(https);
 }
 
 module.exports = { patchHttp };
\ No newline at end of file
@@ -29,6 +29,8 @@ uint64_t init = 0;
     writer->json_keyvalue("type", "marking");                                \
   } else if (type == GCType::kGCTypeProcessWeakCallbacks) {                  \
     writer->json_keyvalue("type", "weakcallbacks");                          \
+  } else if (IsMinorMarkCompact(type)) {                                     \
+    writer->json_keyvalue("type", "minor_marksweep");                        \
   } else {                                                                   \
     writer->json_keyvalue("type", static_cast<int>(type));                   \
   }                                                                          \
@@ -13,13 +13,20 @@ using nlohmann::json;
 using std::exception;
 using std::string;
 
-#define HANDLE_COMMANDS(cmd_str, handle)                              \
-  if (strcmp(cmd.c_str(), #cmd_str) == 0) {                           \
-    handle(                                                           \
-        parsed, FmtMessage,                                           \
-        [traceid](json data) { SuccessValue(traceid, data); },        \
-        [traceid](string message) { ErrorValue(traceid, message); }); \
-    handled = true;                                                   \
+#define HANDLE_COMMANDS(cmd_str, handle)                                \
+  if (strcmp(cmd.c_str(), #cmd_str) == 0) {                             \
+    try {                                                               \
+      handle(                                                           \
+          parsed, FmtMessage,                                           \
+          [traceid](json data) { SuccessValue(traceid, data); },        \
+          [traceid](string message) { ErrorValue(traceid, message); }); \
+    } catch (exception &e) {                                            \
+      Error("parser", "handle command %s error: %s", #cmd_str,          \
+            e.what());                                                  \
+      ErrorValue(traceid, FmtMessage("handle command %s error: %s",     \
+                                     #cmd_str, e.what()));              \
+    }                                                                   \
+    handled = true;                                                     \
   }
 
 void ParseCmd(char *command) {
@@ -32,10 +39,6 @@ void ParseCmd(char *command) {
     return;
   }
 
-  // handle cmd
-  bool handled = false;
-  string cmd = parsed["cmd"];
-
   // get traceid
   XpfError err;
   string traceid = GetJsonValue<string>(parsed, "traceid", err);
@@ -45,6 +48,15 @@ void ParseCmd(char *command) {
     return;
   }
 
+  // handle cmd
+  bool handled = false;
+  string cmd = GetJsonValue<string>(parsed, "cmd", err);
+  if (err.Fail()) {
+    ErrorValue(traceid,
+               FmtMessage("cmd should be passed in: %s", err.GetErrMessage()));
+    return;
+  }
+
   // get version
   HANDLE_COMMANDS(check_version, GetXprofilerVersion)
 
@@ -1,3 +1,9 @@
+#ifndef __STDC_FORMAT_MACROS
+#define __STDC_FORMAT_MACROS
+#endif
+
+#include <inttypes.h>
+
 #include "gc.h"
 
 #include "../library/common.h"
@@ -11,6 +17,9 @@ using v8::GCType;
 static gc_statistics_t *gc_statistics = new gc_statistics_t;
 static uv_mutex_t gc_mutex;
 
+// gc longer than 5min is treated as illegal record
+static const unsigned int kMaxGcDuration = 5 * 60 * 1000;  // ms
+
 unsigned int TotalGcTimes() {
   if (gc_statistics == nullptr) {
     return 0;
@@ -18,7 +27,7 @@ unsigned int TotalGcTimes() {
   return gc_statistics->total_gc_times;
 }
 
-unsigned int TotalGcDuration() {
+uint64_t TotalGcDuration() {
   if (gc_statistics == nullptr) {
     return 0;
   }
@@ -43,22 +52,24 @@ NAN_GC_CALLBACK(GCEpilogueCallback) {
     return;
   }
 
-  gc_statistics->total_gc_times++;
   unsigned int duration = (now - start) / 10e5;  // cost, ms
 
+  // reset gc start time
+  gc_statistics->start() = 0;
+
   // check duration is legal
-  if (duration >= 5 * 60 * 1000) {
+  if (duration >= kMaxGcDuration) {
+    gc_statistics->illegal_gc_times_last_record++;
     uv_mutex_unlock(&gc_mutex);
     return;
   }
 
-  // reset gc start time
-  gc_statistics->start() = 0;
-
+  gc_statistics->total_gc_times++;
   gc_statistics->total_gc_duration += duration;
   gc_statistics->gc_time_during_last_record += duration;
 
-  if (type == GCType::kGCTypeScavenge) {
+  // minor mark-compact is young generation gc as scavenge
+  if (type == GCType::kGCTypeScavenge || IsMinorMarkCompact(type)) {
     gc_statistics->total_scavange_duration += duration;
     gc_statistics->scavange_duration_last_record += duration;
   }
@@ -87,8 +98,8 @@ void WriteGcStatusToLog(bool log_format_alinode) {
   uv_mutex_lock(&gc_mutex);
   if (log_format_alinode)
     Info("gc",
-         "gc_time_during_last_min: %lu, total: %lu, scavange_duration: %lu, "
-         "marksweep_duration: %lu",
+         "gc_time_during_last_min: %" PRIu64 ", total: %" PRIu64
+         ", scavange_duration: %" PRIu64 ", marksweep_duration: %" PRIu64,
          gc_statistics->gc_time_during_last_record,
          gc_statistics->total_gc_duration,
          gc_statistics->scavange_duration_last_record,
@@ -97,14 +108,14 @@ void WriteGcStatusToLog(bool log_format_alinode) {
     Info("gc",
          "uptime: %lu, "
          "total_gc_times: %u, "
-         "total_gc_duration: %lu, "
-         "total_scavange_duration: %lu, "
-         "total_marksweep_duration: %lu, "
-         "total_incremental_marking_duration: %lu, "
-         "gc_time_during_last_record: %lu, "
-         "scavange_duration_last_record: %lu, "
-         "marksweep_duration_last_record: %lu, "
-         "incremental_marking_duration_last_record: %lu",
+         "total_gc_duration: %" PRIu64 ", "
+         "total_scavange_duration: %" PRIu64 ", "
+         "total_marksweep_duration: %" PRIu64 ", "
+         "total_incremental_marking_duration: %" PRIu64 ", "
+         "gc_time_during_last_record: %" PRIu64 ", "
+         "scavange_duration_last_record: %" PRIu64 ", "
+         "marksweep_duration_last_record: %" PRIu64 ", "
+         "incremental_marking_duration_last_record: %" PRIu64,
          GetUptime(),  // uptime, s
          // total
          gc_statistics->total_gc_times, gc_statistics->total_gc_duration,
@@ -116,6 +127,9 @@ void WriteGcStatusToLog(bool log_format_alinode) {
          gc_statistics->scavange_duration_last_record,
          gc_statistics->marksweep_duration_last_record,
          gc_statistics->incremental_marking_duration_last_record);
+  if (gc_statistics->illegal_gc_times_last_record != 0)
+    Error("gc", "%u gc record(s) ignored for duration >= %u ms.",
+          gc_statistics->illegal_gc_times_last_record, kMaxGcDuration);
   // reset last record
   gc_statistics->reset();
   uv_mutex_unlock(&gc_mutex);
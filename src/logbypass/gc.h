@@ -9,15 +9,17 @@ typedef struct GcStatistics {
   // total gc times
   unsigned int total_gc_times = 0;
   // total gc duration
-  unsigned long total_gc_duration = 0;
-  unsigned long total_scavange_duration = 0;
-  unsigned long total_marksweep_duration = 0;
-  unsigned long total_incremental_marking_duration = 0;
+  uint64_t total_gc_duration = 0;
+  uint64_t total_scavange_duration = 0;
+  uint64_t total_marksweep_duration = 0;
+  uint64_t total_incremental_marking_duration = 0;
   // last record
-  unsigned long gc_time_during_last_record = 0;
-  unsigned long scavange_duration_last_record = 0;
-  unsigned long marksweep_duration_last_record = 0;
-  unsigned long incremental_marking_duration_last_record = 0;
+  uint64_t gc_time_during_last_record = 0;
+  uint64_t scavange_duration_last_record = 0;
+  uint64_t marksweep_duration_last_record = 0;
+  uint64_t incremental_marking_duration_last_record = 0;
+  // illegal gc ignored
+  unsigned int illegal_gc_times_last_record = 0;
 
   // record start
   uint64_t &start() { return start_; }
@@ -29,16 +31,30 @@ typedef struct GcStatistics {
     scavange_duration_last_record = 0;
     marksweep_duration_last_record = 0;
     incremental_marking_duration_last_record = 0;
+    illegal_gc_times_last_record = 0;
   }
 
  private:
   uint64_t start_ = 0;
 } gc_statistics_t;
 
+// minor mark-compact (runs with --minor-mc) added in v8 5.8 (node 8), renamed
+// to minor mark-sweep in v8 11 with the old name kept as a deprecated alias,
+// and the old name removed in v8 12 (node 22), ref: v8::GCType
+inline bool IsMinorMarkCompact(v8::GCType type) {
+#if (NODE_MODULE_VERSION >= 127)
+  return type == v8::GCType::kGCTypeMinorMarkSweep;
+#elif (NODE_MODULE_VERSION >= 57)
+  return type == v8::GCType::kGCTypeMinorMarkCompact;
+#else
+  return false;
+#endif
+}
+
 int InitGcStatusHooks();
 void WriteGcStatusToLog(bool log_format_alinode);
 unsigned int TotalGcTimes();
-unsigned int TotalGcDuration();
+uint64_t TotalGcDuration();
 }  // namespace xprofiler
 
 #endif
\ No newline at end of file
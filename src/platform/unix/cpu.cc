@@ -1,25 +1,45 @@
 #if defined(__APPLE__) || defined(__linux__)
+#include <time.h>
+
 #include "uv.h"
 
 namespace xprofiler {
+// process cpu time, ns
+static uint64_t GetProcessCpuTime() {
+#ifdef CLOCK_PROCESS_CPUTIME_ID
+  // nanosecond precision, prefer it when available
+  struct timespec ts;
+  if (clock_gettime(CLOCK_PROCESS_CPUTIME_ID, &ts) == 0) {
+    return static_cast<uint64_t>(ts.tv_sec) * 1000000000 + ts.tv_nsec;
+  }
+#endif
+  // fallback to clock()
+  return static_cast<uint64_t>(clock()) * 1000000000 / CLOCKS_PER_SEC;
+}
+
 double GetNowCpuUsage() {
   static uint64_t last_time = 0;
-  static clock_t last_cpu_usage = 0;
+  static uint64_t last_cpu_usage = 0;
 
   // first time
   if (last_time == 0 || last_cpu_usage == 0) {
     last_time = uv_hrtime();
-    last_cpu_usage = clock();
+    last_cpu_usage = GetProcessCpuTime();
     return -1;
   }
 
   // calculate cpu usage
-  double cpu_now_ = 100 * (clock() - last_cpu_usage) / CLOCKS_PER_SEC /
-                    ((uv_hrtime() - last_time) / 10e8);
+  uint64_t now_time = uv_hrtime();
+  uint64_t now_cpu_usage = GetProcessCpuTime();
+  double cpu_now_ = -1;
+  if (now_time > last_time && now_cpu_usage >= last_cpu_usage) {
+    cpu_now_ =
+        100.0 * (now_cpu_usage - last_cpu_usage) / (now_time - last_time);
+  }
 
   // update time & cpu usage
-  last_time = uv_hrtime();
-  last_cpu_usage = clock();
+  last_time = now_time;
+  last_cpu_usage = now_cpu_usage;
 
   return cpu_now_;
 }
@@ -12,6 +12,7 @@ const moment = require('moment');
 const xprofctl = path.join(__dirname, '../bin/xprofctl');
 const xctl = require('../lib/xctl');
 const utils = require('./fixtures/utils');
+const { processAlive } = require('../lib/utils');
 
 const commandTestFixture = require('./fixtures/command.test');
 const { checkProfile } = commandTestFixture;
@@ -35,6 +36,43 @@ function convertOptions(options) {
   return extra;
 }
 
+describe('execute invalid cmd 123', function () {
+  let resByXctl = '';
+  let alive = false;
+  let p;
+  before(async function () {
+    mm(os, 'homedir', () => tmphome);
+    p = cp.fork(path.join(__dirname, './fixtures/non-blocking.js'), {
+      env: Object.assign({}, process.env, {
+        XPROFILER_LOG_DIR: logdir,
+        XPROFILER_UNIT_TEST_TMP_HOMEDIR: tmphome,
+        XPROFILER_LOG_LEVEL: 2,
+        XPROFILER_LOG_TYPE: 1
+      })
+    });
+    await utils.sleep(4500);
+    resByXctl = await xctl(p.pid, 123);
+    await utils.sleep(500);
+    alive = processAlive(p.pid);
+    await new Promise(resolve => p.on('close', resolve));
+  });
+
+  after(function () {
+    mm.restore();
+  });
+
+  it('response with xctl should be error', function () {
+    console.log('xtcl:', JSON.stringify(resByXctl));
+    expect(resByXctl['traceid']).to.be.ok();
+    expect(resByXctl['ok']).not.to.be.ok();
+    expect(/cmd should be passed in/.test(resByXctl['message'])).to.be.ok();
+  });
+
+  it('child process should be still alive', function () {
+    expect(alive).to.be.ok();
+  });
+});
+
 for (let i = 0; i < testConfig.length; i++) {
   const { cmd, options = {}, profileRules, errored = false, xctlRules, xprofctlRules } = testConfig[i];
   for (let j = 0; j < testFiles.length; j++) {
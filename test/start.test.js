@@ -4,15 +4,27 @@ const os = require('os');
 const fs = require('fs');
 const path = require('path');
 const cp = require('child_process');
+const http = require('http');
 const mm = require('mm');
 const expect = require('expect.js');
+const binary = require('@mapbox/node-pre-gyp');
 const xprofiler = require('../xprofiler');
+const binding = require(binary.find(path.resolve(path.join(__dirname, '../package.json'))));
 const utils = require('./fixtures/utils');
 const SPLITTER = '\u0000';
 
 const logdir = utils.createLogDir('logdir_start');
 const tmphome = utils.createLogDir('tmphome_start');
 
+function sendRequest(port) {
+  return new Promise((resolve, reject) => {
+    http.get(`http://localhost:${port}`, res => {
+      res.resume();
+      res.on('end', resolve);
+    }).on('error', reject);
+  });
+}
+
 describe(`xprofiler starting`, function () {
   const invaidPid = 25416;
   const xprofilerPath = path.join(tmphome, '.xprofiler');
@@ -76,6 +88,29 @@ describe(`xprofiler starting`, function () {
     });
   });
 
+  it('http request should be counted once when xprofiler started twice', async function () {
+    let liveRequest = 0;
+    mm(binding, 'addLiveRequest', () => liveRequest++);
+    xprofiler({ log_dir: logdir });
+    xprofiler({ log_dir: logdir });
+    const server = http.createServer((req, res) => res.end('ok'));
+    await new Promise(resolve => server.listen(0, resolve));
+    await sendRequest(server.address().port);
+    server.close();
+    expect(liveRequest).to.be(1);
+  });
+
+  it('http request timeout should use patch_http_timeout of latest start', async function () {
+    let requestTimeout = 0;
+    mm(binding, 'addRequestTimeout', () => requestTimeout++);
+    xprofiler({ log_dir: logdir, patch_http_timeout: 1 });
+    const server = http.createServer((req, res) => setTimeout(() => res.end('ok'), 1500));
+    await new Promise(resolve => server.listen(0, resolve));
+    await sendRequest(server.address().port);
+    server.close();
+    expect(requestTimeout).to.be(1);
+  });
+
   it('duplicate process info not exists in ~/.xprofiler', function () {
     const content = fs.readFileSync(xprofilerPath, 'utf8').trim();
     const aliveProcessInfo = content.split('\n').map(line => line.split(SPLITTER));